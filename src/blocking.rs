@@ -0,0 +1,192 @@
+//! Blocking counterpart of the crate's default async driver.
+//!
+//! Enabled by the `blocking` feature. The API mirrors [`crate::Max7219`] method
+//! for method, but is bound to [`embedded_hal::spi::SpiDevice`] instead of
+//! [`embedded_hal_async::spi::SpiDevice`], so it can be driven from a plain
+//! bare-metal loop without an async executor. Font and number encoding are
+//! shared with the async driver so the two surfaces can't drift.
+
+use embedded_hal::spi::SpiDevice;
+
+use crate::{
+    base_10_bytes, code_b_nibble, hex_bytes, pad_left, ssb_byte, DecodeMode, Error, Register,
+    NUM_DIGITS,
+};
+
+/// A MAX7219 chip, driven with blocking SPI transfers.
+///
+/// This type drives a single chip. Daisy-chained setups are currently only
+/// supported by the async [`crate::chain::Max7219Chain`].
+pub struct Max7219<SPI> {
+    pub spi: SPI,
+}
+
+impl<SPI> Max7219<SPI>
+where
+    SPI: SpiDevice,
+{
+    /// Write a byte to a register on the display chip.
+    fn write_reg(&mut self, register: impl Into<u8>, data: u8) -> Result<(), Error<SPI::Error>> {
+        self.spi
+            .write(&[register.into(), data])
+            .map_err(Error::Spi)
+    }
+
+    /// Power on the display.
+    pub fn power_on(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.write_reg(Register::Power, 0x01)
+    }
+
+    /// Powers off the display.
+    pub fn power_off(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.write_reg(Register::Power, 0x00)
+    }
+
+    /// Clears the display by setting all digits to empty.
+    pub fn clear_display(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.write_raw(&[0; NUM_DIGITS])
+    }
+
+    /// Sets intensity level on the display, from `0x00` (dimmest) to `0x0F` (brightest).
+    pub fn set_intensity(&mut self, intensity: u8) -> Result<(), Error<SPI::Error>> {
+        if intensity > 0x0F {
+            return Err(Error::IntensityOutOfRange);
+        }
+        self.write_reg(Register::Intensity, intensity)
+    }
+
+    /// Sets decode mode to be used on input sent to the display chip.
+    ///
+    /// See [`DecodeMode`] for more information.
+    pub fn set_decode_mode(&mut self, mode: DecodeMode) -> Result<(), Error<SPI::Error>> {
+        self.write_reg(Register::DecodeMode, mode as u8)
+    }
+
+    /// Writes a byte to a digit on the display.
+    ///
+    /// See [`crate::Max7219::write_digit_bytes`] for the segment byte layout.
+    #[inline]
+    pub fn write_digit_bytes(&mut self, digit: u8, value: u8) -> Result<(), Error<SPI::Error>> {
+        if digit >= NUM_DIGITS as u8 {
+            return Err(Error::DigitOutOfRange);
+        }
+        self.write_reg(digit + 1, value)
+    }
+
+    /// Writes byte string to the display
+    ///
+    /// # Arguments
+    ///
+    /// * `string` - the byte string to send 8 bytes long. Unknown characters result in question mark.
+    /// * `dots` - u8 bit array specifying where to put dots in the string (1 = dot, 0 = not)
+    pub fn write_str(
+        &mut self,
+        string: &[u8; NUM_DIGITS],
+        dots: u8,
+    ) -> Result<(), Error<SPI::Error>> {
+        for (i, b) in string.iter().enumerate() {
+            let reg = NUM_DIGITS as u8 - i as u8; // reverse order
+            self.write_reg(reg, ssb_byte(*b, (dots & (1 << i)) != 0))?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes a right justified integer with sign.
+    pub fn write_integer(&mut self, value: i32) -> Result<(), Error<SPI::Error>> {
+        let mut buf = [0u8; 8];
+        let j = base_10_bytes(value, &mut buf).map_err(|_| Error::ValueOutOfRange)?;
+        let buf = pad_left(j);
+        self.write_str(&buf, 0b00000000)
+    }
+
+    /// Writes a right justified hex formatted unsigned integer.
+    pub fn write_hex(&mut self, value: u32) -> Result<(), Error<SPI::Error>> {
+        let mut buf = [0u8; 8];
+        let j = hex_bytes(value, &mut buf);
+        let buf = pad_left(j);
+        self.write_str(&buf, 0b00000000)
+    }
+
+    /// Writes a right justified integer through the chip's built-in Code B
+    /// BCD decoder, rather than building segment patterns in software.
+    ///
+    /// This switches the decode mode to [`DecodeMode::CodeBDigits7_0`], so
+    /// any subsequent [`write_str`][Self::write_str]/[`write_hex`][Self::write_hex]
+    /// call must first restore [`DecodeMode::NoDecode`] via
+    /// [`set_decode_mode`][Self::set_decode_mode].
+    ///
+    /// `dp` sets the decimal point on the digit at that position, using the
+    /// same left-to-right indexing as the `dots` argument of
+    /// [`write_str`][Self::write_str].
+    pub fn write_integer_bcd(&mut self, value: i32, dp: Option<u8>) -> Result<(), Error<SPI::Error>> {
+        let mut buf = [0u8; 8];
+        let j = base_10_bytes(value, &mut buf).map_err(|_| Error::ValueOutOfRange)?;
+        let padded = pad_left(j);
+
+        self.set_decode_mode(DecodeMode::CodeBDigits7_0)?;
+        for (i, b) in padded.iter().enumerate() {
+            let reg = NUM_DIGITS as u8 - i as u8; // reverse order
+            self.write_digit_bcd(reg - 1, code_b_nibble(*b), dp == Some(i as u8))?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes a single Code B nibble code (and optional decimal point) to a
+    /// digit, bypassing software font encoding.
+    ///
+    /// `code` is a Code B nibble: `0x0..=0x9` for digits `0`-`9`, `0x0A` for
+    /// `-`, `0x0B` for `E`, `0x0C` for `H`, `0x0D` for `L`, `0x0E` for `P`, and
+    /// `0x0F` for blank. The chip must be in [`DecodeMode::CodeBDigits7_0`]
+    /// (or the relevant digit enabled under [`DecodeMode::CodeBDigit0`]/
+    /// [`DecodeMode::CodeBDigits3_0`]) for this to take effect.
+    pub fn write_digit_bcd(&mut self, digit: u8, code: u8, dot: bool) -> Result<(), Error<SPI::Error>> {
+        if code > 0x0F {
+            return Err(Error::ValueOutOfRange);
+        }
+        let value = code | if dot { 0b1000_0000 } else { 0 };
+        self.write_digit_bytes(digit, value)
+    }
+
+    /// Writes a raw value to the display.
+    pub fn write_raw(&mut self, raw: &[u8; NUM_DIGITS]) -> Result<(), Error<SPI::Error>> {
+        for (n, b) in raw.iter().enumerate() {
+            self.write_digit_bytes(n as u8, *b)?;
+        }
+        Ok(())
+    }
+
+    /// Enable or disable the display test mode.
+    pub fn set_test(&mut self, enable: bool) -> Result<(), Error<SPI::Error>> {
+        self.write_reg(Register::DisplayTest, if enable { 0x01 } else { 0x00 })
+    }
+
+    /// Create a new instance of the MAX7219 driver.
+    ///
+    /// After creating a new instance, you should call the [`Max7219::init`]
+    /// method to initialize the display.
+    pub const fn new(spi: SPI) -> Self {
+        Self { spi }
+    }
+
+    /// Set the number of digits to scan (display). The value should be between 1 and 8.
+    pub fn set_scan_limit(&mut self, limit: u8) -> Result<(), Error<SPI::Error>> {
+        if !(1..=NUM_DIGITS as u8).contains(&limit) {
+            return Err(Error::ScanLimitOutOfRange);
+        }
+        self.write_reg(Register::ScanLimit, limit - 1)
+    }
+
+    /// Initialize the display with the default settings.
+    pub fn init(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.set_test(false)?;
+        self.set_scan_limit(NUM_DIGITS as u8)?;
+        self.set_decode_mode(DecodeMode::NoDecode)?;
+        self.clear_display()?;
+        self.power_off()?;
+        self.power_on()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+//! Support for daisy-chained (cascaded) MAX7219 chips sharing one SPI `CS` line.
+//!
+//! MAX7219s in a chain pass 16-bit words through DOUT→DIN, so updating device
+//! `i` means clocking out `N` command/data word-pairs in a single transaction
+//! with CS asserted exactly once: the pair for device `i` goes in its own word
+//! slot, and every other device gets a [`Register::Noop`] pair. Because the
+//! first word shifted out lands in the *last* device, word slot `k` (counting
+//! from the start of the transaction) always belongs to device `N - 1 - k`.
+
+use embedded_hal_async::spi::{Operation, SpiDevice};
+
+use crate::{DecodeMode, Error, Register, NUM_DIGITS};
+
+/// `N` MAX7219 chips daisy-chained on a single SPI `CS` line.
+pub struct Max7219Chain<SPI, const N: usize> {
+    pub spi: SPI,
+}
+
+impl<SPI, const N: usize> Max7219Chain<SPI, N>
+where
+    SPI: SpiDevice,
+{
+    /// Create a new instance of the chained MAX7219 driver.
+    ///
+    /// After creating a new instance, you should call the [`Max7219Chain::init`]
+    /// method to initialize every device in the chain.
+    pub const fn new(spi: SPI) -> Self {
+        Self { spi }
+    }
+
+    /// Build the `N` word-pairs for a single transaction, placing `[register, data]`
+    /// in the slot for `device` and `[Register::Noop, 0x00]` everywhere else.
+    fn frame(device: usize, register: u8, data: u8) -> [[u8; 2]; N] {
+        core::array::from_fn(|k| {
+            if N - 1 - k == device {
+                [register, data]
+            } else {
+                [Register::Noop as u8, 0x00]
+            }
+        })
+    }
+
+    /// Build the `N` word-pairs for a single transaction, writing the same
+    /// register on every device with a (possibly) different data byte each.
+    fn frame_all(register: u8, data: &[u8; N]) -> [[u8; 2]; N] {
+        core::array::from_fn(|k| {
+            let device = N - 1 - k;
+            [register, data[device]]
+        })
+    }
+
+    /// Clock a single transaction made up of `N` word-pairs, asserting CS once.
+    async fn write_frame(&mut self, frame: &[[u8; 2]; N]) -> Result<(), Error<SPI::Error>> {
+        let mut ops: [Operation<'_, u8>; N] = core::array::from_fn(|k| Operation::Write(&frame[k]));
+        self.spi.transaction(&mut ops).await.map_err(Error::Spi)
+    }
+
+    /// Write a byte to a register on a single device in the chain (0-indexed,
+    /// where device `0` is the one wired to the controller's DIN).
+    pub async fn write_reg_device(
+        &mut self,
+        device: usize,
+        register: impl Into<u8>,
+        data: u8,
+    ) -> Result<(), Error<SPI::Error>> {
+        if device >= N {
+            return Err(Error::DeviceOutOfRange);
+        }
+        let frame = Self::frame(device, register.into(), data);
+        self.write_frame(&frame).await
+    }
+
+    /// Write the same register on every device in the chain in one transaction,
+    /// with one data byte per device.
+    pub async fn write_all(
+        &mut self,
+        register: impl Into<u8>,
+        data: &[u8; N],
+    ) -> Result<(), Error<SPI::Error>> {
+        let frame = Self::frame_all(register.into(), data);
+        self.write_frame(&frame).await
+    }
+
+    /// Power on every device in the chain.
+    pub async fn power_on(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.write_all(Register::Power, &[0x01; N]).await
+    }
+
+    /// Power off every device in the chain.
+    pub async fn power_off(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.write_all(Register::Power, &[0x00; N]).await
+    }
+
+    /// Enable or disable the display test mode on every device in the chain.
+    pub async fn set_test(&mut self, enable: bool) -> Result<(), Error<SPI::Error>> {
+        let value = if enable { 0x01 } else { 0x00 };
+        self.write_all(Register::DisplayTest, &[value; N]).await
+    }
+
+    /// Set the number of digits to scan (display) on every device. The value
+    /// should be between 1 and 8.
+    pub async fn set_scan_limit(&mut self, limit: u8) -> Result<(), Error<SPI::Error>> {
+        if !(1..=NUM_DIGITS as u8).contains(&limit) {
+            return Err(Error::ScanLimitOutOfRange);
+        }
+        self.write_all(Register::ScanLimit, &[limit - 1; N]).await
+    }
+
+    /// Sets decode mode to be used on input sent to every device in the chain.
+    ///
+    /// See [`DecodeMode`] for more information.
+    pub async fn set_decode_mode(&mut self, mode: DecodeMode) -> Result<(), Error<SPI::Error>> {
+        self.write_all(Register::DecodeMode, &[mode as u8; N]).await
+    }
+
+    /// Sets intensity level on every device in the chain, from `0x00` (dimmest)
+    /// to `0x0F` (brightest).
+    pub async fn set_intensity(&mut self, intensity: u8) -> Result<(), Error<SPI::Error>> {
+        if intensity > 0x0F {
+            return Err(Error::IntensityOutOfRange);
+        }
+        self.write_all(Register::Intensity, &[intensity; N]).await
+    }
+
+    /// Clears every device in the chain by setting all digits to empty.
+    pub async fn clear_display(&mut self) -> Result<(), Error<SPI::Error>> {
+        for digit in 0..NUM_DIGITS as u8 {
+            self.write_all(digit + 1, &[0; N]).await?;
+        }
+        Ok(())
+    }
+
+    /// Initialize every device in the chain with the default settings.
+    pub async fn init(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.set_test(false).await?;
+        self.set_scan_limit(NUM_DIGITS as u8).await?;
+        self.set_decode_mode(DecodeMode::NoDecode).await?;
+        self.clear_display().await?;
+        self.power_off().await?;
+        self.power_on().await?;
+
+        Ok(())
+    }
+}
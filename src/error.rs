@@ -0,0 +1,21 @@
+//! Error type returned by fallible operations on [`crate::Max7219`] and friends.
+
+/// Errors that can occur while driving a MAX7219.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error<E> {
+    /// The underlying SPI transaction failed.
+    Spi(E),
+    /// A value passed to [`crate::Max7219::write_integer`] or
+    /// [`crate::Max7219::write_integer_bcd`] doesn't fit on the display, or a
+    /// code above `0x0F` was passed to [`crate::Max7219::write_digit_bcd`].
+    ValueOutOfRange,
+    /// An intensity outside `0x00..=0x0F` was passed to [`crate::Max7219::set_intensity`].
+    IntensityOutOfRange,
+    /// A scan limit outside `1..=8` was passed to [`crate::Max7219::set_scan_limit`].
+    ScanLimitOutOfRange,
+    /// A digit index outside `0..NUM_DIGITS` was passed to [`crate::Max7219::write_digit_bytes`].
+    DigitOutOfRange,
+    /// A device index outside `0..N` was passed to
+    /// [`crate::chain::Max7219Chain::write_reg_device`].
+    DeviceOutOfRange,
+}
@@ -1,13 +1,26 @@
 //! A platform agnostic driver to interface with the MAX7219 (LED matrix display driver)
 //!
-//! This driver was built using [`embedded-hal-async`] traits.
+//! This driver was built using [`embedded-hal-async`] traits by default. Enable the
+//! `blocking` feature to additionally pull in [`blocking::Max7219`], a parallel API
+//! built on [`embedded-hal`] for bare-metal loops or HALs without an async executor.
 //!
 //! [`embedded-hal-async`]: https://docs.rs/embedded-hal-async/~1.0
+//! [`embedded-hal`]: https://docs.rs/embedded-hal/~1.0
 
 #![deny(unsafe_code)]
 #![deny(warnings)]
 #![no_std]
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod chain;
+mod error;
+pub mod marquee;
+#[cfg(feature = "graphics")]
+pub mod matrix;
+
+pub use error::Error;
+
 use embedded_hal_async::spi::SpiDevice;
 
 /// Digits per display
@@ -50,7 +63,8 @@ pub enum DecodeMode {
 
 /// A MAX7219 chip.
 ///
-/// Currently, this driver does not support daisy-chaining multiple MAX7219 chips.
+/// This type drives a single chip. For daisy-chained (cascaded) MAX7219s
+/// sharing one SPI `CS` line, see [`chain::Max7219Chain`].
 pub struct Max7219<SPI> {
     pub spi: SPI,
 }
@@ -60,34 +74,44 @@ where
     SPI: SpiDevice,
 {
     /// Write a byte to a register on the display chip.
-    async fn write_reg(&mut self, register: impl Into<u8>, data: u8) -> Result<(), SPI::Error> {
-        self.spi.write(&[register.into(), data]).await
+    async fn write_reg(
+        &mut self,
+        register: impl Into<u8>,
+        data: u8,
+    ) -> Result<(), Error<SPI::Error>> {
+        self.spi
+            .write(&[register.into(), data])
+            .await
+            .map_err(Error::Spi)
     }
 
     /// Power on the display.
-    pub async fn power_on(&mut self) -> Result<(), SPI::Error> {
+    pub async fn power_on(&mut self) -> Result<(), Error<SPI::Error>> {
         self.write_reg(Register::Power, 0x01).await
     }
 
     /// Powers off the display.
-    pub async fn power_off(&mut self) -> Result<(), SPI::Error> {
+    pub async fn power_off(&mut self) -> Result<(), Error<SPI::Error>> {
         self.write_reg(Register::Power, 0x00).await
     }
 
     /// Clears the display by setting all digits to empty.
-    pub async fn clear_display(&mut self) -> Result<(), SPI::Error> {
+    pub async fn clear_display(&mut self) -> Result<(), Error<SPI::Error>> {
         self.write_raw(&[0; NUM_DIGITS]).await
     }
 
-    /// Sets intensity level on the display,from `0x00` (dimmest) to `0x0F` (brightest).
-    pub async fn set_intensity(&mut self, intensity: u8) -> Result<(), SPI::Error> {
+    /// Sets intensity level on the display, from `0x00` (dimmest) to `0x0F` (brightest).
+    pub async fn set_intensity(&mut self, intensity: u8) -> Result<(), Error<SPI::Error>> {
+        if intensity > 0x0F {
+            return Err(Error::IntensityOutOfRange);
+        }
         self.write_reg(Register::Intensity, intensity).await
     }
 
     /// Sets decode mode to be used on input sent to the display chip.
     ///
     /// See [`DecodeMode`] for more information.
-    pub async fn set_decode_mode(&mut self, mode: DecodeMode) -> Result<(), SPI::Error> {
+    pub async fn set_decode_mode(&mut self, mode: DecodeMode) -> Result<(), Error<SPI::Error>> {
         self.write_reg(Register::DecodeMode, mode as u8).await
     }
 
@@ -113,7 +137,14 @@ where
     /// To display the number `5`, for example, the byte `0b0101_1011` would be
     /// sent to the display.
     #[inline]
-    pub async fn write_digit_bytes(&mut self, digit: u8, value: u8) -> Result<(), SPI::Error> {
+    pub async fn write_digit_bytes(
+        &mut self,
+        digit: u8,
+        value: u8,
+    ) -> Result<(), Error<SPI::Error>> {
+        if digit >= NUM_DIGITS as u8 {
+            return Err(Error::DigitOutOfRange);
+        }
         self.write_reg(digit + 1, value).await
     }
 
@@ -127,7 +158,7 @@ where
         &mut self,
         string: &[u8; NUM_DIGITS],
         dots: u8,
-    ) -> Result<(), SPI::Error> {
+    ) -> Result<(), Error<SPI::Error>> {
         for (i, b) in string.iter().enumerate() {
             let reg = NUM_DIGITS as u8 - i as u8; // reverse order
             self.write_reg(reg, ssb_byte(*b, (dots & (1 << i)) != 0))
@@ -138,23 +169,74 @@ where
     }
 
     /// Writes a right justified integer with sign.
-    pub async fn write_integer(&mut self, value: i32) -> Result<(), SPI::Error> {
+    pub async fn write_integer(&mut self, value: i32) -> Result<(), Error<SPI::Error>> {
         let mut buf = [0u8; 8];
-        let j = base_10_bytes(value, &mut buf);
-        buf = pad_left(j);
+        let j = base_10_bytes(value, &mut buf).map_err(|_| Error::ValueOutOfRange)?;
+        let buf = pad_left(j);
         self.write_str(&buf, 0b00000000).await
     }
 
     /// Writes a right justified hex formatted integer with sign.
-    pub async fn write_hex(&mut self, value: u32) -> Result<(), SPI::Error> {
+    pub async fn write_hex(&mut self, value: u32) -> Result<(), Error<SPI::Error>> {
         let mut buf = [0u8; 8];
         let j = hex_bytes(value, &mut buf);
-        buf = pad_left(j);
+        let buf = pad_left(j);
         self.write_str(&buf, 0b00000000).await
     }
 
+    /// Writes a right justified integer through the chip's built-in Code B
+    /// BCD decoder, rather than building segment patterns in software.
+    ///
+    /// This switches the decode mode to [`DecodeMode::CodeBDigits7_0`], so
+    /// any subsequent [`write_str`][Self::write_str]/[`write_hex`][Self::write_hex]
+    /// call must first restore [`DecodeMode::NoDecode`] via
+    /// [`set_decode_mode`][Self::set_decode_mode].
+    ///
+    /// `dp` sets the decimal point on the digit at that position, using the
+    /// same left-to-right indexing as the `dots` argument of
+    /// [`write_str`][Self::write_str].
+    pub async fn write_integer_bcd(
+        &mut self,
+        value: i32,
+        dp: Option<u8>,
+    ) -> Result<(), Error<SPI::Error>> {
+        let mut buf = [0u8; 8];
+        let j = base_10_bytes(value, &mut buf).map_err(|_| Error::ValueOutOfRange)?;
+        let padded = pad_left(j);
+
+        self.set_decode_mode(DecodeMode::CodeBDigits7_0).await?;
+        for (i, b) in padded.iter().enumerate() {
+            let reg = NUM_DIGITS as u8 - i as u8; // reverse order
+            self.write_digit_bcd(reg - 1, code_b_nibble(*b), dp == Some(i as u8))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes a single Code B nibble code (and optional decimal point) to a
+    /// digit, bypassing software font encoding.
+    ///
+    /// `code` is a Code B nibble: `0x0..=0x9` for digits `0`-`9`, `0x0A` for
+    /// `-`, `0x0B` for `E`, `0x0C` for `H`, `0x0D` for `L`, `0x0E` for `P`, and
+    /// `0x0F` for blank. The chip must be in [`DecodeMode::CodeBDigits7_0`]
+    /// (or the relevant digit enabled under [`DecodeMode::CodeBDigit0`]/
+    /// [`DecodeMode::CodeBDigits3_0`]) for this to take effect.
+    pub async fn write_digit_bcd(
+        &mut self,
+        digit: u8,
+        code: u8,
+        dot: bool,
+    ) -> Result<(), Error<SPI::Error>> {
+        if code > 0x0F {
+            return Err(Error::ValueOutOfRange);
+        }
+        let value = code | if dot { 0b1000_0000 } else { 0 };
+        self.write_digit_bytes(digit, value).await
+    }
+
     /// Writes a raw value to the display.
-    pub async fn write_raw(&mut self, raw: &[u8; NUM_DIGITS]) -> Result<(), SPI::Error> {
+    pub async fn write_raw(&mut self, raw: &[u8; NUM_DIGITS]) -> Result<(), Error<SPI::Error>> {
         for (n, b) in raw.iter().enumerate() {
             self.write_digit_bytes(n as u8, *b).await?;
         }
@@ -162,7 +244,7 @@ where
     }
 
     /// Enable or disable the display test mode.
-    pub async fn set_test(&mut self, enable: bool) -> Result<(), SPI::Error> {
+    pub async fn set_test(&mut self, enable: bool) -> Result<(), Error<SPI::Error>> {
         self.write_reg(Register::DisplayTest, if enable { 0x01 } else { 0x00 })
             .await
     }
@@ -176,12 +258,15 @@ where
     }
 
     /// Set the number of digits to scan (display). The value should be between 1 and 8.
-    pub async fn set_scan_limit(&mut self, limit: u8) -> Result<(), SPI::Error> {
+    pub async fn set_scan_limit(&mut self, limit: u8) -> Result<(), Error<SPI::Error>> {
+        if !(1..=NUM_DIGITS as u8).contains(&limit) {
+            return Err(Error::ScanLimitOutOfRange);
+        }
         self.write_reg(Register::ScanLimit, limit - 1).await
     }
 
     /// Initialize the display with the default settings.
-    pub async fn init(&mut self) -> Result<(), SPI::Error> {
+    pub async fn init(&mut self) -> Result<(), Error<SPI::Error>> {
         self.set_test(false).await?;
         self.set_scan_limit(NUM_DIGITS as u8).await?;
         self.set_decode_mode(DecodeMode::NoDecode).await?;
@@ -196,7 +281,7 @@ where
 ///
 /// Translate alphanumeric ASCII bytes into segment set bytes
 ///
-fn ssb_byte(b: u8, dot: bool) -> u8 {
+pub(crate) fn ssb_byte(b: u8, dot: bool) -> u8 {
     let mut result = match b as char {
         ' ' => 0b0000_0000, // "blank"
         '.' => 0b1000_0000,
@@ -248,15 +333,17 @@ fn ssb_byte(b: u8, dot: bool) -> u8 {
     result
 }
 
-/// Convert the integer into an integer byte Sequence
-fn base_10_bytes(mut n: i32, buf: &mut [u8]) -> &[u8] {
+/// Convert the integer into an integer byte Sequence.
+///
+/// Returns `Err(())` if `n` doesn't fit on [`NUM_DIGITS`] digits (including sign).
+pub(crate) fn base_10_bytes(mut n: i32, buf: &mut [u8]) -> Result<&[u8], ()> {
     let mut sign: bool = false;
     if n == 0 {
-        return b"0";
+        return Ok(b"0");
     }
     //don't overflow the display
     if !(-9999999..=99999999).contains(&n) {
-        return b"Err";
+        return Err(());
     }
     if n < 0 {
         n = -n;
@@ -274,11 +361,11 @@ fn base_10_bytes(mut n: i32, buf: &mut [u8]) -> &[u8] {
     }
     let slice = &mut buf[..i];
     slice.reverse();
-    &*slice
+    Ok(&*slice)
 }
 
 /// Convert the integer into a hexidecimal byte sequence
-fn hex_bytes(mut n: u32, buf: &mut [u8]) -> &[u8] {
+pub(crate) fn hex_bytes(mut n: u32, buf: &mut [u8]) -> &[u8] {
     if n == 0 {
         return b"0";
     }
@@ -312,8 +399,19 @@ fn hex_bytes(mut n: u32, buf: &mut [u8]) -> &[u8] {
     &*slice
 }
 
+/// Translate an ASCII byte (as produced by [`base_10_bytes`]/[`pad_left`])
+/// into its Code B nibble code: `0`-`9` map to themselves, `-` to `0x0A`,
+/// and anything else (the blank padding) to `0x0F`.
+pub(crate) fn code_b_nibble(b: u8) -> u8 {
+    match b {
+        b'0'..=b'9' => b - b'0',
+        b'-' => 0x0A,
+        _ => 0x0F,
+    }
+}
+
 /// Take a byte slice and pad the left hand side
-fn pad_left(val: &[u8]) -> [u8; 8] {
+pub(crate) fn pad_left(val: &[u8]) -> [u8; 8] {
     assert!(val.len() <= 8);
     let size: usize = 8;
     let pos: usize = val.len();
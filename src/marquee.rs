@@ -0,0 +1,78 @@
+//! Scrolling marquee for messages longer than [`NUM_DIGITS`].
+//!
+//! [`write_str`][crate::Max7219::write_str] is hard-wired to exactly
+//! `NUM_DIGITS` bytes, so anything longer can't be shown in one write.
+//! [`Marquee`] keeps an internal window offset into an arbitrary-length
+//! message and exposes [`Marquee::step`], which renders the current
+//! 8-character window and advances by one position on every call, wrapping
+//! around with a configurable gap of blanks between repetitions.
+
+use embedded_hal_async::spi::SpiDevice;
+
+use crate::{ssb_byte, Error, Max7219, NUM_DIGITS};
+
+/// A scrolling window into a message longer than [`NUM_DIGITS`] characters.
+pub struct Marquee<'a> {
+    message: &'a [u8],
+    gap: usize,
+    offset: usize,
+}
+
+impl<'a> Marquee<'a> {
+    /// Create a new marquee over `message`, with `gap` blank characters
+    /// separating the end of the message from its next repetition.
+    pub const fn new(message: &'a [u8], gap: usize) -> Self {
+        Self {
+            message,
+            gap,
+            offset: 0,
+        }
+    }
+
+    /// Length of one full loop: the message plus its trailing blank gap.
+    fn period(&self) -> usize {
+        (self.message.len() + self.gap).max(1)
+    }
+
+    /// The character `i` positions after the current offset, or a blank if
+    /// that position falls in the gap.
+    fn byte_at(&self, i: usize) -> u8 {
+        let pos = (self.offset + i) % self.period();
+        self.message.get(pos).copied().unwrap_or(b' ')
+    }
+
+    /// Render the current 8-character window as segment bytes, ready for
+    /// [`Max7219::write_raw`].
+    ///
+    /// `write_raw`'s `raw[n]` lands on `Digit n`, while the crate's display
+    /// convention (set by [`Max7219::write_str`]) puts the first logical
+    /// character on `Digit7`, so the window is built in reverse.
+    pub fn window(&self) -> [u8; NUM_DIGITS] {
+        core::array::from_fn(|i| ssb_byte(self.byte_at(NUM_DIGITS - 1 - i), false))
+    }
+
+    /// Render the current window to `display` and advance the window by one
+    /// position, wrapping back to the start once the gap has scrolled past.
+    pub async fn step<SPI>(&mut self, display: &mut Max7219<SPI>) -> Result<(), Error<SPI::Error>>
+    where
+        SPI: SpiDevice,
+    {
+        display.write_raw(&self.window()).await?;
+        self.offset = (self.offset + 1) % self.period();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_puts_first_character_on_digit7() {
+        let marquee = Marquee::new(b"HELLO", 1);
+        // `write_raw`'s index 7 is the slot that lands on `Digit7`, which is
+        // where the first logical character of the message belongs.
+        assert_eq!(marquee.window()[7], ssb_byte(b'H', false));
+        assert_eq!(marquee.window()[6], ssb_byte(b'E', false));
+    }
+}
@@ -0,0 +1,146 @@
+//! `embedded-graphics` support for driving an 8×8 LED matrix (or a row of
+//! daisy-chained matrices) instead of a 7-segment font.
+//!
+//! Enabled by the `graphics` feature. [`Matrix`] keeps an `[u8; 8]` row
+//! buffer where digit register `Digit0..Digit7` maps to one pixel row and
+//! bit position maps to column; [`Matrix::flush`] pushes all eight rows via
+//! [`crate::Max7219::write_raw`]. [`ChainMatrix`] does the same across a
+//! [`crate::chain::Max7219Chain`], giving an `8·N×8` canvas.
+
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Size},
+    pixelcolor::BinaryColor,
+    Pixel,
+};
+use embedded_hal_async::spi::SpiDevice;
+
+use crate::{chain::Max7219Chain, Max7219, Register, NUM_DIGITS};
+
+/// A single 8×8 LED matrix, addressable as an `embedded-graphics`
+/// [`DrawTarget`].
+///
+/// Draws are buffered in memory; call [`Matrix::flush`] to push the buffer
+/// to the display.
+pub struct Matrix<SPI> {
+    display: Max7219<SPI>,
+    rows: [u8; NUM_DIGITS],
+}
+
+impl<SPI> Matrix<SPI>
+where
+    SPI: SpiDevice,
+{
+    /// Wrap an initialized [`Max7219`] display as a matrix canvas.
+    pub const fn new(display: Max7219<SPI>) -> Self {
+        Self {
+            display,
+            rows: [0; NUM_DIGITS],
+        }
+    }
+
+    /// Push the buffered rows to the display.
+    pub async fn flush(&mut self) -> Result<(), crate::Error<SPI::Error>> {
+        self.display.write_raw(&self.rows).await
+    }
+}
+
+impl<SPI> OriginDimensions for Matrix<SPI> {
+    fn size(&self) -> Size {
+        Size::new(NUM_DIGITS as u32, NUM_DIGITS as u32)
+    }
+}
+
+impl<SPI> DrawTarget for Matrix<SPI> {
+    type Color = BinaryColor;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(coord, color) in pixels.into_iter() {
+            let (Ok(x), Ok(y)) = (u8::try_from(coord.x), u8::try_from(coord.y)) else {
+                continue;
+            };
+            if x >= NUM_DIGITS as u8 || y >= NUM_DIGITS as u8 {
+                continue;
+            }
+
+            let bit = 1 << x;
+            match color {
+                BinaryColor::Off => self.rows[y as usize] &= !bit,
+                BinaryColor::On => self.rows[y as usize] |= bit,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A row of `N` daisy-chained 8×8 LED matrices, addressable as a single
+/// `8·N`-wide `embedded-graphics` [`DrawTarget`].
+pub struct ChainMatrix<SPI, const N: usize> {
+    chain: Max7219Chain<SPI, N>,
+    rows: [[u8; NUM_DIGITS]; N],
+}
+
+impl<SPI, const N: usize> ChainMatrix<SPI, N>
+where
+    SPI: SpiDevice,
+{
+    /// Wrap an initialized [`Max7219Chain`] as a matrix canvas.
+    pub const fn new(chain: Max7219Chain<SPI, N>) -> Self {
+        Self {
+            chain,
+            rows: [[0; NUM_DIGITS]; N],
+        }
+    }
+
+    /// Push the buffered rows to every device in the chain, one register
+    /// (i.e. one pixel row across all devices) per transaction.
+    pub async fn flush(&mut self) -> Result<(), crate::Error<SPI::Error>> {
+        for n in 0..NUM_DIGITS {
+            let data: [u8; N] = core::array::from_fn(|device| self.rows[device][n]);
+            self.chain
+                .write_all(Register::Digit0 as u8 + n as u8, &data)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+impl<SPI, const N: usize> OriginDimensions for ChainMatrix<SPI, N> {
+    fn size(&self) -> Size {
+        Size::new((NUM_DIGITS * N) as u32, NUM_DIGITS as u32)
+    }
+}
+
+impl<SPI, const N: usize> DrawTarget for ChainMatrix<SPI, N> {
+    type Color = BinaryColor;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(coord, color) in pixels.into_iter() {
+            let (Ok(x), Ok(y)) = (usize::try_from(coord.x), usize::try_from(coord.y)) else {
+                continue;
+            };
+            if x >= NUM_DIGITS * N || y >= NUM_DIGITS {
+                continue;
+            }
+
+            let device = x / NUM_DIGITS;
+            let local_x = x % NUM_DIGITS;
+            let bit: u8 = 1 << local_x;
+            match color {
+                BinaryColor::Off => self.rows[device][y] &= !bit,
+                BinaryColor::On => self.rows[device][y] |= bit,
+            }
+        }
+
+        Ok(())
+    }
+}